@@ -0,0 +1,28 @@
+import os
+import tempfile
+import unittest
+import sys
+
+sys.path.append("../..")
+
+from tools.toolbox.python_subprocess import write_to_file, run_python_script
+
+
+class TestPythonSubprocess(unittest.TestCase):
+
+    SCRIPT = "a = 1\r\nb =\t'tab'\nc = 'µ – ✓'\nd = '\\x00'\r\nprint(a, b, len(c), len(d))\n"
+
+    def test_write_to_file_preserves_content(self) -> None:
+        with tempfile.TemporaryDirectory() as temp_dir:
+            path = os.path.join(temp_dir, "script.py")
+            write_to_file(path, self.SCRIPT)
+            with open(path, "rb") as f:
+                self.assertEqual(f.read(), self.SCRIPT.encode("utf-8"))
+
+    def test_run_python_script_with_mixed_whitespace(self) -> None:
+        output = run_python_script(self.SCRIPT, blocking=True, python_exe=sys.executable)
+        self.assertIsNotNone(output)
+        self.assertIn("1 tab 5 1", str(output))
+
+if __name__ == '__main__':
+    unittest.main()
@@ -7,7 +7,9 @@ import shutil
 
 
 def write_to_file(file_name: str, content: str) -> None:
-    with open(file_name, "w", encoding="utf-8") as f:
+    # newline="" keeps the script byte-for-byte; text mode would otherwise
+    # translate line endings (e.g. CRLF -> CRCRLF on Windows).
+    with open(file_name, "w", encoding="utf-8", newline="") as f:
         f.write(content)
 
 